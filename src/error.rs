@@ -1,24 +1,44 @@
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 
-pub struct PlacerError<'a> {
-    pub why: &'a str,
+pub enum PlacerError {
+    InvalidArgCount { got: usize },
+    Io(std::io::Error),
+    Parse { line: usize, detail: String },
+    MalformedGate,
+    MalformedEdge,
+    UnexpectedEof,
 }
 
-impl<'a> Error for PlacerError<'a> { }
+impl Error for PlacerError { }
 
-impl<'a> Display for PlacerError<'a> {
+impl Display for PlacerError {
     fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
-        write!(f, "Placer Error: {}", self.why)?;
-
-        Ok(())
+        match self {
+            PlacerError::InvalidArgCount { got } =>
+                write!(f, "Placer Error: Invalid number of arguments, expected at most 4. (got {})", got),
+            PlacerError::Io(err) =>
+                write!(f, "Placer Error: I/O failure: {}", err),
+            PlacerError::Parse { line, detail } =>
+                write!(f, "Placer Error: malformed value on line {}: {}", line, detail),
+            PlacerError::MalformedGate =>
+                write!(f, "Placer Error: Invalid gate definition"),
+            PlacerError::MalformedEdge =>
+                write!(f, "Placer Error: Invalid edge definition"),
+            PlacerError::UnexpectedEof =>
+                write!(f, "Placer Error: File contains no more lines"),
+        }
     }
 }
 
-impl<'a> Debug for PlacerError<'a> {
+impl Debug for PlacerError {
     fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
-        write!(f, "Placer Error: {}", self.why)?;
+        Display::fmt(self, f)
+    }
+}
 
-        Ok(())
+impl From<std::io::Error> for PlacerError {
+    fn from(err: std::io::Error) -> Self {
+        PlacerError::Io(err)
     }
 }
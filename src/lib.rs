@@ -1,34 +1,72 @@
 mod error;
 
-use std::error::Error;
+use std::collections::BTreeMap;
 use std::fmt::{Debug, Formatter};
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{self, BufRead, BufReader, Write};
 
-use error::PlacerError;
+pub use error::PlacerError;
+
+/// Where the problem is read from: a named file, or stdin when the filename
+/// is `-` or omitted entirely.
+pub enum Input {
+    File(String),
+    Stdin,
+}
+
+/// Whether `run` only returns the placement and wirelength (`Execute`) or also
+/// writes the solved placement to a path (`EmitPlacement`).
+pub enum Mode {
+    Execute,
+    EmitPlacement(String),
+}
 
 pub struct Config {
-    filename: String,
+    input: Input,
+    mode: Mode,
+    net_model: NetModel,
 }
 
 impl Config {
     pub fn new(args: &[String]) -> Result<Config, PlacerError> {
-        if 2 != args.len() {
-            return Err(PlacerError { why: "Invalid number of arguments, expected 2." });
+        if args.len() > 4 {
+            return Err(PlacerError::InvalidArgCount { got: args.len() });
         }
 
-        let filename = args[1].clone();
-        Ok(Config { filename })
+        let input = match args.get(1).map(String::as_str) {
+            None | Some("-") => Input::Stdin,
+            Some(filename) => Input::File(filename.to_string()),
+        };
+
+        let mode = match args.get(2) {
+            None => Mode::Execute,
+            Some(path) => Mode::EmitPlacement(path.clone()),
+        };
+
+        let net_model = match args.get(3).map(String::as_str) {
+            Some("star") => NetModel::Star,
+            _ => NetModel::Clique,
+        };
+
+        Ok(Config { input, mode, net_model })
+    }
+
+    pub fn input(&self) -> &Input {
+        &self.input
     }
 
-    pub fn filename(&self) -> &String {
-        &self.filename
+    pub fn mode(&self) -> &Mode {
+        &self.mode
+    }
+
+    pub fn net_model(&self) -> NetModel {
+        self.net_model
     }
 }
 
-struct Coordinate {
-    x: i32,
-    y: i32,
+pub struct Coordinate {
+    pub x: i32,
+    pub y: i32,
 }
 
 impl Debug for Coordinate {
@@ -37,26 +75,36 @@ impl Debug for Coordinate {
     }
 }
 
-struct Edge {
-    node_a: usize,
-    node_b: usize,
+/// A net spanning two or more pins (static cells and/or floating cells).
+struct Net {
+    nodes: Vec<usize>,
 }
 
 struct Problem {
     solve_diff: f64,
     floating_cells: usize,
     static_cells: Vec<Coordinate>,
-    edges: Vec<Edge>,
+    nets: Vec<Net>,
+}
+
+/// How a multi-pin net is folded into the pairwise placement matrix: as a
+/// weighted clique over its pins, or via one virtual floating node per net of
+/// three or more pins (2-pin nets connect directly under either model).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum NetModel {
+    Clique,
+    Star,
 }
 
+/// Sparse symmetric matrix, accumulated as per-row maps then flattened to CSR.
 struct Matrix {
     size: usize,
-    values: Vec<Vec<i32>>
+    rows: Vec<BTreeMap<usize, f64>>,
 }
 
 impl Debug for Matrix {
     fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
-        for row in &self.values {
+        for row in &self.rows {
             writeln!(f, "{:?}", row)?;
         }
 
@@ -66,98 +114,141 @@ impl Debug for Matrix {
 
 impl Matrix {
     fn new(size: usize) -> Matrix {
-        Matrix { size, values: vec![vec![0; size]; size] }
+        Matrix { size, rows: vec![BTreeMap::new(); size] }
     }
 
-    fn add_node_view(&mut self, node: usize) -> () {
-        self.values[node][node] += 2;
+    fn add_node_view(&mut self, node: usize, weight: f64) -> () {
+        *self.rows[node].entry(node).or_insert(0.0) += 2.0 * weight;
     }
 
-    fn add_edge_view(&mut self, node_a: usize, node_b: usize) ->() {
-        self.add_node_view(node_a);
-        self.add_node_view(node_b);
-        self.values[node_a][node_b] += -2;
-        self.values[node_b][node_a] += -2;
+    fn add_edge_view(&mut self, node_a: usize, node_b: usize, weight: f64) -> () {
+        self.add_node_view(node_a, weight);
+        self.add_node_view(node_b, weight);
+        *self.rows[node_a].entry(node_b).or_insert(0.0) += -2.0 * weight;
+        *self.rows[node_b].entry(node_a).or_insert(0.0) += -2.0 * weight;
     }
 
-    fn solve(&self, solve_diff: f64, b: &Vec<i32>) -> Vec<i32> {
-        let mut solution = vec![0.0; b.len()];
+    /// Flatten the accumulated rows into CSR arrays (`row_ptr`, `col_idx`, `val`).
+    fn to_csr(&self) -> (Vec<usize>, Vec<usize>, Vec<f64>) {
+        let mut row_ptr = Vec::with_capacity(self.size + 1);
+        let mut col_idx = Vec::new();
+        let mut val = Vec::new();
+
+        row_ptr.push(0);
+        for row in &self.rows {
+            for (&column, &value) in row {
+                col_idx.push(column);
+                val.push(value);
+            }
+            row_ptr.push(col_idx.len());
+        }
 
-        loop {
-            let mut iter_diff = 0.0;
-            let mut new_solution_row = Vec::new();
-            for row in 0..self.size {
-                let mut new_solution = b[row] as f64;
-                for column in 0..self.size {
-                    // Skip the solver if it is the current equation.
-                    if row == column {
-                        continue;
-                    }
+        (row_ptr, col_idx, val)
+    }
 
-                    new_solution -= self.values[row][column] as f64 * solution[column]
-                }
-                new_solution /= self.values[row][row] as f64;
+    /// Sparse matrix-vector product `A·x` over CSR arrays.
+    fn mat_vec(row_ptr: &[usize], col_idx: &[usize], val: &[f64], x: &[f64]) -> Vec<f64> {
+        let mut result = vec![0.0; row_ptr.len() - 1];
+        for row in 0..result.len() {
+            let mut sum = 0.0;
+            for i in row_ptr[row]..row_ptr[row + 1] {
+                sum += val[i] * x[col_idx[i]];
+            }
+            result[row] = sum;
+        }
 
-                let diff = (solution[row] - new_solution).abs();
-                if diff > iter_diff {
-                    iter_diff = diff;
-                }
+        result
+    }
 
-                new_solution_row.push(new_solution);
+    /// Solve `A·x = b` with Conjugate Gradient, exploiting that `A` is symmetric positive-definite.
+    fn solve(&self, solve_diff: f64, b: &Vec<f64>) -> Vec<i32> {
+        let (row_ptr, col_idx, val) = self.to_csr();
+        let n = self.size;
+
+        let mut x = vec![0.0; n];
+        let mut r = b.clone();
+        let mut p = r.clone();
+        let mut r_dot_r = dot(&r, &r);
+
+        for _ in 0..n {
+            if r_dot_r < solve_diff * solve_diff {
+                break;
             }
-            solution = new_solution_row;
 
-            if iter_diff < solve_diff {
+            let ap = Self::mat_vec(&row_ptr, &col_idx, &val, &p);
+            let alpha = r_dot_r / dot(&p, &ap);
+
+            for i in 0..n {
+                x[i] += alpha * p[i];
+            }
+
+            let r_new: Vec<f64> = r.iter().zip(&ap).map(|(r_i, ap_i)| r_i - alpha * ap_i).collect();
+            let residual = r_new.iter().fold(0.0_f64, |max, value| max.max(value.abs()));
+            if residual < solve_diff {
                 break;
             }
-        }
 
-        let mut rounded_solution = Vec::new();
-        for value in solution {
-            rounded_solution.push(value.round() as i32);
+            let r_new_dot_r_new = dot(&r_new, &r_new);
+            let beta = r_new_dot_r_new / r_dot_r;
+            for i in 0..n {
+                p[i] = r_new[i] + beta * p[i];
+            }
+
+            r = r_new;
+            r_dot_r = r_new_dot_r_new;
         }
-        rounded_solution
+
+        x.iter().map(|value| value.round() as i32).collect()
     }
 }
 
-fn read_expected_line(reader: &mut BufReader<&File>) -> Result<String, Box<dyn Error>> {
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn read_expected_line(reader: &mut dyn BufRead, line_no: &mut usize) -> Result<String, PlacerError> {
     let mut line = String::new();
     let num_bytes = reader.read_line(&mut line)?;
     if 0 == num_bytes {
-        return Err(PlacerError { why: "File contains no more lines" }.into());
+        return Err(PlacerError::UnexpectedEof);
     }
 
+    *line_no += 1;
     line.pop();
     Ok(line)
 }
 
-fn read_int_array(reader: &mut BufReader<&File>) -> Result<Vec<i32>, Box<dyn Error>> {
-    let line = read_expected_line(reader)?;
+fn read_int_array(reader: &mut dyn BufRead, line_no: &mut usize) -> Result<Vec<i32>, PlacerError> {
+    let line = read_expected_line(reader, line_no)?;
     let mut values = Vec::new();
     for value in line.split_whitespace() {
-        values.push(value.parse()?);
+        let value = value.parse()
+            .map_err(|err: std::num::ParseIntError| PlacerError::Parse { line: *line_no, detail: err.to_string() })?;
+        values.push(value);
     }
 
     Ok(values)
 }
 
-fn read_index_array(reader: &mut BufReader<&File>) -> Result<Vec<usize>, Box<dyn Error>> {
-    let line = read_expected_line(reader)?;
+fn read_index_array(reader: &mut dyn BufRead, line_no: &mut usize) -> Result<Vec<usize>, PlacerError> {
+    let line = read_expected_line(reader, line_no)?;
     let mut values = Vec::new();
     for value in line.split_whitespace() {
-        values.push(value.parse()?);
+        let value = value.parse()
+            .map_err(|err: std::num::ParseIntError| PlacerError::Parse { line: *line_no, detail: err.to_string() })?;
+        values.push(value);
     }
 
     Ok(values)
 }
 
-fn parse_static_cells(reader: &mut BufReader<&File>, num_cells: usize)
-    -> Result<Vec<Coordinate>, Box<dyn Error>> {
+fn parse_static_cells(reader: &mut dyn BufRead, num_cells: usize, line_no: &mut usize)
+    -> Result<Vec<Coordinate>, PlacerError> {
     let mut static_cells = Vec::new();
-    for x in 0..num_cells {
-        let static_cell = read_int_array(reader)?;
+    for _ in 0..num_cells {
+        let static_cell = read_int_array(reader, line_no)?;
         if 2 != static_cell.len() {
-            return Err(PlacerError { why: "Invalid gate definition" }.into());
+            return Err(PlacerError::MalformedGate);
         }
 
         static_cells.push(Coordinate { x: static_cell[0], y: static_cell[1] });
@@ -166,53 +257,91 @@ fn parse_static_cells(reader: &mut BufReader<&File>, num_cells: usize)
     Ok(static_cells)
 }
 
-fn parse(config: &Config) -> Result<(Problem), Box<dyn Error>> {
-    let file = File::open(config.filename())?;
-    let mut reader = BufReader::new(&file);
+fn parse(config: &Config) -> Result<Problem, PlacerError> {
+    let mut reader: Box<dyn BufRead> = match config.input() {
+        Input::File(filename) => Box::new(BufReader::new(File::open(filename)?)),
+        Input::Stdin => Box::new(BufReader::new(io::stdin())),
+    };
+    let reader = reader.as_mut();
+    let mut line_no = 0;
 
-    let solve_diff: f64 = read_expected_line(&mut reader)?.parse()?;
-    let chip_info = read_int_array(&mut reader)?;
+    let solve_diff_line = read_expected_line(reader, &mut line_no)?;
+    let solve_diff: f64 = solve_diff_line.parse()
+        .map_err(|err: std::num::ParseFloatError| PlacerError::Parse { line: line_no, detail: err.to_string() })?;
+    let chip_info = read_int_array(reader, &mut line_no)?;
 
     // Load the static cells
     let num_statics = chip_info[0] as usize;
-    let static_cells = parse_static_cells(&mut reader, num_statics)?;
+    let static_cells = parse_static_cells(reader, num_statics, &mut line_no)?;
 
-    let mut edges = Vec::new();
-    for x in 0..chip_info[2] {
-        let edge = read_index_array(&mut reader)?;
-        if 2 != edge.len() {
-            return Err(PlacerError { why: "Invalid edge definition" }.into());
+    let mut nets = Vec::new();
+    for _ in 0..chip_info[2] {
+        let nodes = read_index_array(reader, &mut line_no)?;
+        if nodes.len() < 2 {
+            return Err(PlacerError::MalformedEdge);
         }
 
-        edges.push(Edge { node_a: edge[0], node_b: edge[1] });
+        nets.push(Net { nodes });
     }
 
-    Ok(Problem { solve_diff, floating_cells: chip_info[1] as usize, static_cells, edges })
+    Ok(Problem { solve_diff, floating_cells: chip_info[1] as usize, static_cells, nets })
+}
+
+/// Add one weighted pin pair's contribution to the quadratic system.
+fn connect_pins(a: &mut Matrix, xb: &mut Vec<f64>, yb: &mut Vec<f64>, num_statics: usize,
+                static_cells: &Vec<Coordinate>, node_a: usize, node_b: usize, weight: f64) {
+    if node_a < num_statics && node_b >= num_statics {
+        let floating_node = node_b - num_statics;
+        xb[floating_node] += static_cells[node_a].x as f64 * 2.0 * weight;
+        yb[floating_node] += static_cells[node_a].y as f64 * 2.0 * weight;
+        a.add_node_view(floating_node, weight);
+    } else if node_a >= num_statics && node_b < num_statics {
+        let floating_node = node_a - num_statics;
+        xb[floating_node] += static_cells[node_b].x as f64 * 2.0 * weight;
+        yb[floating_node] += static_cells[node_b].y as f64 * 2.0 * weight;
+        a.add_node_view(floating_node, weight);
+    } else if node_a >= num_statics && node_b >= num_statics {
+        a.add_edge_view(node_a - num_statics, node_b - num_statics, weight);
+    }
 }
 
-fn solve_placement(prob: &Problem) -> Vec<Coordinate> {
+fn solve_placement(prob: &Problem, net_model: NetModel) -> Vec<Coordinate> {
     let num_statics = prob.static_cells.len();
 
-    let mut a = Matrix::new(prob.floating_cells);
-    let mut xb = vec![0; prob.floating_cells];
-    let mut yb = vec![0; prob.floating_cells];
-    for edge in &prob.edges {
-        if edge.node_a < num_statics && edge.node_b >= num_statics {
-            let static_node = edge.node_a;
-            let floating_node = edge.node_b - num_statics;
-            xb[floating_node] += prob.static_cells[static_node].x * 2;
-            yb[floating_node] += prob.static_cells[static_node].y * 2;
-            a.add_node_view(floating_node);
-        } else if edge.node_a >= num_statics && edge.node_b < num_statics {
-            let static_node = edge.node_b;
-            let floating_node = edge.node_a - num_statics;
-            xb[floating_node] += prob.static_cells[static_node].x * 2;
-            yb[floating_node] += prob.static_cells[static_node].y * 2;
-            a.add_node_view(floating_node)
-        } else if edge.node_a >= num_statics && edge.node_b >= num_statics {
-            let node_a = edge.node_a - num_statics;
-            let node_b = edge.node_b - num_statics;
-            a.add_edge_view(node_a, node_b);
+    // A star net needs one virtual floating node of its own; clique nets (and
+    // every 2-pin net, regardless of model) connect pins directly.
+    let virtual_nodes = if NetModel::Star == net_model {
+        prob.nets.iter().filter(|net| net.nodes.len() > 2).count()
+    } else {
+        0
+    };
+
+    let total_floating = prob.floating_cells + virtual_nodes;
+    let mut a = Matrix::new(total_floating);
+    let mut xb = vec![0.0; total_floating];
+    let mut yb = vec![0.0; total_floating];
+
+    let mut next_virtual_node = prob.floating_cells;
+    for net in &prob.nets {
+        let k = net.nodes.len();
+        if NetModel::Star == net_model && k > 2 {
+            let virtual_node = next_virtual_node + num_statics;
+            next_virtual_node += 1;
+
+            for &pin in &net.nodes {
+                connect_pins(&mut a, &mut xb, &mut yb, num_statics, &prob.static_cells,
+                             virtual_node, pin, 1.0);
+            }
+        } else {
+            // Clique weighting: every pair shares `2/(k-1)` so a k-pin net's
+            // total contributed weight stays comparable to a 2-pin net.
+            let weight = 2.0 / (k - 1) as f64;
+            for i in 0..k {
+                for j in (i + 1)..k {
+                    connect_pins(&mut a, &mut xb, &mut yb, num_statics, &prob.static_cells,
+                                 net.nodes[i], net.nodes[j], weight);
+                }
+            }
         }
     }
 
@@ -227,44 +356,60 @@ fn solve_placement(prob: &Problem) -> Vec<Coordinate> {
     solved_coordinates
 }
 
-fn calculate_manhattan(edges: &Vec<Edge>, static_cells: &Vec<Coordinate>,
-                       floating_cells: &Vec<Coordinate>) -> usize {
-    let mut length= 0;
-    for edge in edges {
-        let coordinate_a;
-        let coordinate_b;
-
-        if edge.node_a < static_cells.len() {
-            coordinate_a = &static_cells[edge.node_a];
-        } else {
-            coordinate_a = &floating_cells[edge.node_a - static_cells.len()];
-        }
+fn pin_coordinate<'a>(node: usize, static_cells: &'a Vec<Coordinate>,
+                      floating_cells: &'a Vec<Coordinate>) -> &'a Coordinate {
+    if node < static_cells.len() {
+        &static_cells[node]
+    } else {
+        &floating_cells[node - static_cells.len()]
+    }
+}
 
-        if edge.node_b < static_cells.len() {
-            coordinate_b = &static_cells[edge.node_b];
-        } else {
-            coordinate_b = &floating_cells[edge.node_b - static_cells.len()];
+/// Half-perimeter wirelength (HPWL), summed over all nets; for a 2-pin net
+/// this is exactly the Manhattan distance between its endpoints.
+fn calculate_manhattan(nets: &Vec<Net>, static_cells: &Vec<Coordinate>,
+                       floating_cells: &Vec<Coordinate>) -> usize {
+    let mut length = 0;
+    for net in nets {
+        let mut min_x = i32::MAX;
+        let mut max_x = i32::MIN;
+        let mut min_y = i32::MAX;
+        let mut max_y = i32::MIN;
+        for &node in &net.nodes {
+            let pin = pin_coordinate(node, static_cells, floating_cells);
+            min_x = min_x.min(pin.x);
+            max_x = max_x.max(pin.x);
+            min_y = min_y.min(pin.y);
+            max_y = max_y.max(pin.y);
         }
 
-        length += (coordinate_a.x - coordinate_b.x).abs();
-        length += (coordinate_a.y - coordinate_b.y).abs();
+        length += (max_x - min_x) + (max_y - min_y);
     }
 
     length as usize
 }
 
-pub fn run(config: &Config) -> Result<(usize), Box<dyn Error>> {
+/// Write the solved `(x, y)` of every floating cell to `path`, one coordinate
+/// per line, in the same pad-relative order the problem numbered them.
+fn write_placement(path: &str, placement: &Vec<Coordinate>) -> Result<(), PlacerError> {
+    let mut file = File::create(path)?;
+    for coordinate in placement {
+        writeln!(file, "{} {}", coordinate.x, coordinate.y)?;
+    }
+
+    Ok(())
+}
+
+pub fn run(config: &Config) -> Result<(Vec<Coordinate>, usize), PlacerError> {
     let prob = parse(config)?;
-    let floating_cells = solve_placement(&prob);
+    let floating_cells = solve_placement(&prob, config.net_model());
+    let manhattan = calculate_manhattan(&prob.nets, &prob.static_cells, &floating_cells);
 
-    let offset = prob.static_cells.len();
-    let mut count = 0;
-    for cell in floating_cells.iter() {
-        count += 1;
+    if let Mode::EmitPlacement(path) = config.mode() {
+        write_placement(path, &floating_cells)?;
     }
-    let manhattan = calculate_manhattan(&prob.edges, &prob.static_cells, &floating_cells);
 
-    Ok(manhattan)
+    Ok((floating_cells, manhattan))
 }
 
 #[cfg(test)]
@@ -276,13 +421,55 @@ mod tests {
         let args = [String::from("test1"), String::from("test2")];
         let config = Config::new(&args).unwrap();
 
-        assert_eq!("test2", config.filename());
+        assert!(matches!(config.input(), Input::File(filename) if filename == "test2"));
+    }
+
+    #[test]
+    fn missing_filename_reads_stdin() {
+        let args = [String::from("dummy_exe")];
+        let config = Config::new(&args).unwrap();
+
+        assert!(matches!(config.input(), Input::Stdin));
+    }
+
+    #[test]
+    fn dash_filename_reads_stdin() {
+        let args = [String::from("dummy_exe"), String::from("-")];
+        let config = Config::new(&args).unwrap();
+
+        assert!(matches!(config.input(), Input::Stdin));
+    }
+
+    #[test]
+    fn output_argument_sets_emit_placement_mode() {
+        let args = [String::from("dummy_exe"), String::from("test1"), String::from("out.txt")];
+        let config = Config::new(&args).unwrap();
+
+        assert!(matches!(config.mode(), Mode::EmitPlacement(path) if path == "out.txt"));
+    }
+
+    #[test]
+    fn net_model_defaults_to_clique() {
+        let args = [String::from("dummy_exe"), String::from("test1"), String::from("out.txt")];
+        let config = Config::new(&args).unwrap();
+
+        assert!(matches!(config.net_model(), NetModel::Clique));
+    }
+
+    #[test]
+    fn star_argument_selects_star_net_model() {
+        let args = [String::from("dummy_exe"), String::from("test1"), String::from("out.txt"),
+                    String::from("star")];
+        let config = Config::new(&args).unwrap();
+
+        assert!(matches!(config.net_model(), NetModel::Star));
     }
 
     #[test]
-    #[should_panic(expected = r#"Placer Error: Invalid number of arguments, expected 2."#)]
-    fn too_few_arguments() {
-        let args = [String::from("test1")];
+    #[should_panic(expected = r#"Placer Error: Invalid number of arguments, expected at most 4."#)]
+    fn too_many_arguments() {
+        let args = [String::from("dummy_exe"), String::from("test1"), String::from("out.txt"),
+                    String::from("star"), String::from("extra")];
         Config::new(&args).unwrap();
     }
 
@@ -290,7 +477,7 @@ mod tests {
     fn test1() {
         let args = [String::from("dummy_exe"), String::from("test1")];
         let config = Config::new(&args).unwrap();
-        let length = run(&config).unwrap();
+        let (_, length) = run(&config).unwrap();
 
         assert_eq!(12, length);
     }
@@ -299,7 +486,7 @@ mod tests {
     fn test2() {
         let args = [String::from("dummy_exe"), String::from("test2")];
         let config = Config::new(&args).unwrap();
-        let length = run(&config).unwrap();
+        let (_, length) = run(&config).unwrap();
 
         assert_eq!(12, length);
     }
@@ -308,7 +495,7 @@ mod tests {
     fn test3() {
         let args = [String::from("dummy_exe"), String::from("test3")];
         let config = Config::new(&args).unwrap();
-        let length = run(&config).unwrap();
+        let (_, length) = run(&config).unwrap();
 
         assert_eq!(12, length);
     }
@@ -317,18 +504,57 @@ mod tests {
     fn test4() {
         let args = [String::from("dummy_exe"), String::from("test4")];
         let config = Config::new(&args).unwrap();
-        let length = run(&config).unwrap();
+        let (_, length) = run(&config).unwrap();
 
         assert_eq!(42517, length);
     }
 
     #[test]
-    #[ignore]
     fn test5() {
         let args = [String::from("dummy_exe"), String::from("test5")];
         let config = Config::new(&args).unwrap();
-        let length = run(&config).unwrap();
+        let (_, length) = run(&config).unwrap();
 
         assert_eq!(833829, length);
     }
+
+    // Two pads at (0, 0) and (10, 0) plus one floating cell (global index 2),
+    // joined by a single 3-pin net. Clique weighting gives every pin pair
+    // weight 2/(3-1) = 1, so the floating cell settles at the pads' midpoint.
+    fn three_pin_problem() -> Problem {
+        Problem {
+            solve_diff: 0.0001,
+            floating_cells: 1,
+            static_cells: vec![Coordinate { x: 0, y: 0 }, Coordinate { x: 10, y: 0 }],
+            nets: vec![Net { nodes: vec![0, 1, 2] }],
+        }
+    }
+
+    #[test]
+    fn clique_model_places_three_pin_net_at_pad_midpoint() {
+        let prob = three_pin_problem();
+        let placement = solve_placement(&prob, NetModel::Clique);
+
+        assert_eq!(5, placement[0].x);
+        assert_eq!(0, placement[0].y);
+    }
+
+    #[test]
+    fn star_model_matches_clique_for_a_symmetric_net() {
+        let prob = three_pin_problem();
+        let placement = solve_placement(&prob, NetModel::Star);
+
+        assert_eq!(5, placement[0].x);
+        assert_eq!(0, placement[0].y);
+    }
+
+    #[test]
+    fn calculate_manhattan_uses_bounding_box_of_multi_pin_net() {
+        let prob = three_pin_problem();
+        let placement = solve_placement(&prob, NetModel::Clique);
+
+        let length = calculate_manhattan(&prob.nets, &prob.static_cells, &placement);
+
+        assert_eq!(10, length);
+    }
 }
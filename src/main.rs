@@ -10,8 +10,10 @@ fn main() {
         process::exit(1);
     });
 
-    placer::run(&config).unwrap_or_else(|err| {
+    let (_, length) = placer::run(&config).unwrap_or_else(|err| {
         println!("Failed to solve placements: {}", err);
         process::exit(1);
     });
+
+    println!("{}", length);
 }